@@ -0,0 +1,57 @@
+use std::env;
+
+/// Runtime configuration for cross-cutting middleware, loaded from env vars
+/// so deployments can tune it without a code change.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub cors_allowed_origins: Vec<String>,
+    /// Empty means "allow any method", same convention as
+    /// `cors_allowed_origins`. Raw strings; parsed into `http::Method` where
+    /// the CORS layer is built so this module doesn't need to know about it.
+    pub cors_allowed_methods: Vec<String>,
+    /// Empty means "allow any header", same convention as
+    /// `cors_allowed_origins`.
+    pub cors_allowed_headers: Vec<String>,
+    pub request_timeout_secs: u64,
+    pub max_request_body_bytes: usize,
+}
+
+/// Splits a comma-separated env var into its trimmed, non-empty entries.
+fn comma_separated_env(key: &str) -> Vec<String> {
+    env::var(key)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 10;
+const DEFAULT_MAX_REQUEST_BODY_BYTES: usize = 64 * 1024;
+
+impl Config {
+    pub fn from_env() -> Self {
+        let cors_allowed_origins = comma_separated_env("CORS_ALLOWED_ORIGINS");
+        let cors_allowed_methods = comma_separated_env("CORS_ALLOWED_METHODS");
+        let cors_allowed_headers = comma_separated_env("CORS_ALLOWED_HEADERS");
+
+        let request_timeout_secs = env::var("REQUEST_TIMEOUT_SECS")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_REQUEST_TIMEOUT_SECS);
+
+        let max_request_body_bytes = env::var("MAX_REQUEST_BODY_BYTES")
+            .ok()
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(DEFAULT_MAX_REQUEST_BODY_BYTES);
+
+        Self {
+            cors_allowed_origins,
+            cors_allowed_methods,
+            cors_allowed_headers,
+            request_timeout_secs,
+            max_request_body_bytes,
+        }
+    }
+}
@@ -18,9 +18,24 @@ pub enum AppError {
     #[error("Database error: {0}")]
     SqlxError(#[from] sqlx::Error),
 
+    #[error("Password hashing error: {0}")]
+    BcryptError(#[from] bcrypt::BcryptError),
+
+    #[error("Token error: {0}")]
+    JwtError(#[from] jsonwebtoken::errors::Error),
+
     #[error("Item not found")]
     NotFound,
 
+    #[error("{0}")]
+    Conflict(String),
+
+    #[error("Invalid credentials")]
+    Unauthorized,
+
+    #[error("You do not have access to this resource")]
+    Forbidden,
+
     #[error("An internal server error occurred")]
     InternalServerError,
 }
@@ -46,10 +61,33 @@ impl IntoResponse for AppError {
                     "An internal database error occurred.".to_string(),
                 )
             }
+            AppError::BcryptError(ref e) => {
+                tracing::error!("Password hashing error: {:?}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "An internal error occurred while processing credentials.".to_string(),
+                )
+            }
+            AppError::JwtError(ref e) => {
+                tracing::error!("JWT error: {:?}", e);
+                (
+                    StatusCode::UNAUTHORIZED,
+                    "Invalid or expired token.".to_string(),
+                )
+            }
             AppError::NotFound => (
                 StatusCode::NOT_FOUND,
                 "The requested item was not found.".to_string(),
             ),
+            AppError::Conflict(ref message) => (StatusCode::CONFLICT, message.clone()),
+            AppError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                "Invalid credentials.".to_string(),
+            ),
+            AppError::Forbidden => (
+                StatusCode::FORBIDDEN,
+                "You do not have access to this resource.".to_string(),
+            ),
             AppError::InternalServerError => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "An unexpected error occurred.".to_string(),
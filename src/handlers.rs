@@ -1,20 +1,71 @@
 use axum::{
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
 };
-use chrono::{DateTime, Utc};
 use uuid::Uuid;
 use validator::Validate;
 
 use crate::{
     AppState,
+    auth::{self, AuthUser},
     errors::{AppError, AppResult},
-    models::{CreateTodo, Todo, UpdateTodo},
+    models::{
+        AuthResponse, CreateTodo, CreateUser, ListTodosParams, LoginUser, PaginatedTodos,
+        ReplaceTodo, Todo, UpdateTodo, User,
+    },
 };
 
+pub async fn signup(
+    State(app_state): State<AppState>,
+    Json(payload): Json<CreateUser>,
+) -> AppResult<(StatusCode, Json<User>)> {
+    if let Err(validation_errors) = payload.validate() {
+        tracing::error!("Signup validation failed: {:?}", validation_errors);
+        return Err(validation_errors.into());
+    }
+
+    let password_hash = auth::hash_password(&payload.password)?;
+    let user = app_state
+        .user_repository
+        .create(&payload.username, &password_hash)
+        .await?;
+
+    tracing::info!("Successfully signed up user: {}", user.username);
+
+    Ok((StatusCode::CREATED, Json(user)))
+}
+
+pub async fn login(
+    State(app_state): State<AppState>,
+    Json(payload): Json<LoginUser>,
+) -> AppResult<Json<AuthResponse>> {
+    if let Err(validation_errors) = payload.validate() {
+        tracing::error!("Login validation failed: {:?}", validation_errors);
+        return Err(validation_errors.into());
+    }
+
+    let user = app_state
+        .user_repository
+        .find_by_username(&payload.username)
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    if !auth::verify_password(&payload.password, &user.password_hash)? {
+        tracing::warn!("Invalid password for user: {}", payload.username);
+        return Err(AppError::Unauthorized);
+    }
+
+    let token = auth::issue_token(user.id, &app_state.jwt_secret)?;
+
+    tracing::info!("Successfully logged in user: {}", payload.username);
+
+    Ok(Json(AuthResponse { token }))
+}
+
 pub async fn create_todo(
     State(app_state): State<AppState>,
+    AuthUser(user_id): AuthUser,
     Json(payload): Json<CreateTodo>,
 ) -> AppResult<(StatusCode, Json<Todo>)> {
     if let Err(validation_errors) = payload.validate() {
@@ -22,116 +73,41 @@ pub async fn create_todo(
         return Err(validation_errors.into());
     }
 
-    tracing::info!("Validated payload: {:?}", payload);
-
-    let todo_id = Uuid::new_v4();
-    let now = Utc::now();
-
-    let todo_id_string = todo_id.to_string();
-
-    let record = sqlx::query!(
-        r#"
-        INSERT INTO todos (id, title, description, completed, created_at, updated_at)
-        VALUES ($1, $2, $3, $4, $5, $6)
-        RETURNING
-            id,
-            title,
-            description,
-            completed,
-            created_at AS "created_at: DateTime<Utc>", -- Decode as DateTime<Utc>
-            updated_at AS "updated_at: DateTime<Utc>"  -- Decode as DateTime<Utc>
-        "#,
-        todo_id_string,
-        payload.title,
-        payload.description,
-        false,
-        now,
-        now
-    )
-    .fetch_one(&app_state.db_pool)
-    .await?;
-
-    let new_todo = Todo {
-        id: Uuid::parse_str(&record.id)?,
-        title: record.title,
-        description: record.description,
-        completed: record.completed,
-        created_at: record.created_at,
-        updated_at: record.updated_at,
-    };
+    let new_todo = app_state.todo_repository.create(user_id, &payload).await?;
 
     tracing::info!("Successfully created todo: {:?}", new_todo);
-
     Ok((StatusCode::CREATED, Json(new_todo)))
 }
 
-pub async fn get_todos(State(app_state): State<AppState>) -> AppResult<Json<Vec<Todo>>> {
-    tracing::info!("Fetching all todos");
-
-    let records = sqlx::query!(
-        r#"
-        SELECT id, title, description, completed, created_at AS "created_at: DateTime<Utc>", updated_at AS "updated_at: DateTime<Utc>"
-        FROM todos
-        ORDER BY created_at DESC -- Optional: order by creation date
-        "#
-    )
-    .fetch_all(&app_state.db_pool)
-    .await?;
-
-    let mut todos = Vec::with_capacity(records.len());
-    for record in records {
-        todos.push(Todo {
-            id: Uuid::parse_str(&record.id)?,
-            title: record.title,
-            description: record.description,
-            completed: record.completed,
-            created_at: record.created_at,
-            updated_at: record.updated_at,
-        });
-    }
+pub async fn get_todos(
+    State(app_state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Query(params): Query<ListTodosParams>,
+) -> AppResult<Json<PaginatedTodos>> {
+    tracing::info!("Fetching todos for user {} with params: {:?}", user_id, params);
 
-    tracing::info!("Successfully fetched {} todos", todos.len());
-    Ok(Json(todos))
+    let page = app_state.todo_repository.list(user_id, &params).await?;
+
+    tracing::info!("Successfully fetched {} of {} todos", page.items.len(), page.total);
+    Ok(Json(page))
 }
 
 pub async fn get_todo_by_id(
     State(app_state): State<AppState>,
+    AuthUser(user_id): AuthUser,
     Path(id): Path<Uuid>,
 ) -> AppResult<Json<Todo>> {
     tracing::info!("Fetching todo by id: {}", id);
 
-    let id_as_string = id.to_string();
-
-    let optional_record = sqlx::query!(
-        r#"
-        SELECT id, title, description, completed, created_at AS "created_at: DateTime<Utc>", updated_at AS "updated_at: DateTime<Utc>"
-        FROM todos
-        WHERE id = $1
-        "#,
-        id_as_string
-    )
-    .fetch_optional(&app_state.db_pool)
-    .await?;
-
-    if let Some(record) = optional_record {
-        let todo = Todo {
-            id: Uuid::parse_str(&record.id)?,
-            title: record.title,
-            description: record.description,
-            completed: record.completed,
-            created_at: record.created_at,
-            updated_at: record.updated_at,
-        };
-        tracing::info!("Successfully fetched todo: {:?}", todo);
-        Ok(Json(todo))
-    } else {
-        tracing::warn!("Todo with id {} not found", id);
-        Err(AppError::NotFound)
-    }
+    let todo = app_state.todo_repository.get(user_id, id).await?;
+
+    tracing::info!("Successfully fetched todo: {:?}", todo);
+    Ok(Json(todo))
 }
 
-pub async fn update_todo(
+pub async fn patch_todo(
     State(app_state): State<AppState>,
+    AuthUser(user_id): AuthUser,
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateTodo>,
 ) -> AppResult<Json<Todo>> {
@@ -139,82 +115,51 @@ pub async fn update_todo(
         tracing::error!("Update validation failed: {:?}", validation_errors);
         return Err(validation_errors.into());
     }
-    tracing::info!("Updating todo id: {} with payload: {:?}", id, payload);
-
-    let id_as_string = id.to_string();
-
-    let current_record = sqlx::query!(
-        r#"SELECT title, description, completed, created_at FROM todos WHERE id = $1"#,
-        id_as_string
-    )
-    .fetch_optional(&app_state.db_pool)
-    .await?;
-
-    let current_todo_values = match current_record {
-        Some(record) => record,
-        None => {
-            tracing::warn!("Todo with id {} not found for update", id);
-            return Err(AppError::NotFound);
-        }
-    };
-
-    let final_title = payload.title.unwrap_or(current_todo_values.title);
-    let final_description = payload.description;
-    let final_completed = payload.completed.unwrap_or(current_todo_values.completed);
-    let updated_at_ts = Utc::now();
-
-    let updated_record = sqlx::query!(
-        r#"
-        UPDATE todos
-        SET title = $1, description = $2, completed = $3, updated_at = $4
-        WHERE id = $5
-        RETURNING id, title, description, completed, created_at AS "created_at: DateTime<Utc>", updated_at AS "updated_at: DateTime<Utc>"
-        "#,
-        final_title,
-        final_description,
-        final_completed,
-        updated_at_ts,
-        id_as_string
-    )
-    .fetch_one(&app_state.db_pool)
-    .await?;
-
-    let todo = Todo {
-        id: Uuid::parse_str(&updated_record.id)?,
-        title: updated_record.title,
-        description: updated_record.description,
-        completed: updated_record.completed,
-        created_at: updated_record.created_at,
-        updated_at: updated_record.updated_at,
-    };
+    tracing::info!("Patching todo id: {} with payload: {:?}", id, payload);
+
+    let todo = app_state
+        .todo_repository
+        .update(user_id, id, &payload)
+        .await?;
 
     tracing::info!("Successfully updated todo: {:?}", todo);
     Ok(Json(todo))
 }
 
+/// Full replacement of a todo. Unlike `patch_todo`, every field is mandatory
+/// and the row is overwritten wholesale. If `id` doesn't exist yet, it is
+/// created at that id (`201`); if it does, it's replaced in place (`200`).
+pub async fn put_todo(
+    State(app_state): State<AppState>,
+    AuthUser(user_id): AuthUser,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<ReplaceTodo>,
+) -> AppResult<(StatusCode, Json<Todo>)> {
+    if let Err(validation_errors) = payload.validate() {
+        tracing::error!("Replace validation failed: {:?}", validation_errors);
+        return Err(validation_errors.into());
+    }
+    tracing::info!("Replacing todo id: {} with payload: {:?}", id, payload);
+
+    let (todo, created) = app_state
+        .todo_repository
+        .replace(user_id, id, &payload)
+        .await?;
+    let status = if created { StatusCode::CREATED } else { StatusCode::OK };
+
+    tracing::info!("Successfully replaced todo: {:?}", todo);
+    Ok((status, Json(todo)))
+}
+
 pub async fn delete_todo(
     State(app_state): State<AppState>,
+    AuthUser(user_id): AuthUser,
     Path(id): Path<Uuid>,
 ) -> AppResult<StatusCode> {
     tracing::info!("Attempting to delete todo with id: {}", id);
 
-    let id_as_string = id.to_string();
-
-    let result = sqlx::query!(
-        r#"
-        DELETE FROM todos
-        WHERE id = $1
-        "#,
-        id_as_string
-    )
-    .execute(&app_state.db_pool)
-    .await?;
-
-    if result.rows_affected() == 0 {
-        tracing::warn!("Todo with id {} not found for deletion", id);
-        Err(AppError::NotFound)
-    } else {
-        tracing::info!("Successfully deleted todo with id: {}", id);
-        Ok(StatusCode::NO_CONTENT)
-    }
+    app_state.todo_repository.delete(user_id, id).await?;
+
+    tracing::info!("Successfully deleted todo with id: {}", id);
+    Ok(StatusCode::NO_CONTENT)
 }
@@ -0,0 +1,26 @@
+use axum::{Json, extract::State, http::StatusCode};
+use serde_json::{Value, json};
+
+use crate::AppState;
+
+/// Liveness probe: the process is up and able to handle requests.
+pub async fn health_check() -> StatusCode {
+    StatusCode::OK
+}
+
+/// Readiness probe: the process is up AND the database is reachable.
+pub async fn db_health_check(State(app_state): State<AppState>) -> (StatusCode, Json<Value>) {
+    match app_state.user_repository.ping().await {
+        Ok(()) => (StatusCode::OK, Json(json!({ "status": "ok" }))),
+        Err(e) => {
+            tracing::error!("Database readiness check failed: {:?}", e);
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({
+                    "status": "error",
+                    "message": "Database is not reachable",
+                })),
+            )
+        }
+    }
+}
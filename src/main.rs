@@ -1,42 +1,125 @@
+mod auth;
+mod config;
 mod errors;
 mod handlers;
+mod health;
 mod models;
+mod repository;
 
+use axum::error_handling::HandleErrorLayer;
+use axum::http::StatusCode;
 use axum::routing::post;
-use axum::{Router, routing::get};
+use axum::{BoxError, Router, routing::get};
 use dotenvy::dotenv;
-use sqlx::{SqlitePool, sqlite::SqlitePoolOptions};
+use sqlx::mysql::MySqlPoolOptions;
+use sqlx::postgres::PgPoolOptions;
+use sqlx::sqlite::SqlitePoolOptions;
 use std::env;
 use std::fs;
 use std::net::SocketAddr;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::ServiceBuilder;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tower_http::limit::RequestBodyLimitLayer;
+use tower_http::trace::TraceLayer;
 use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
 
+use crate::config::Config;
+use crate::repository::{
+    Dialect, DynTodoRepository, DynUserRepository, MySqlTodoRepository, MySqlUserRepository,
+    PostgresTodoRepository, PostgresUserRepository, SqliteTodoRepository, SqliteUserRepository,
+};
+
 #[derive(Clone)]
 pub struct AppState {
-    db_pool: SqlitePool,
+    jwt_secret: String,
+    todo_repository: DynTodoRepository,
+    user_repository: DynUserRepository,
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    dotenv().ok();
+async fn handle_middleware_error(err: BoxError) -> (StatusCode, String) {
+    if err.is::<tower::timeout::error::Elapsed>() {
+        (
+            StatusCode::REQUEST_TIMEOUT,
+            "Request took too long to process".to_string(),
+        )
+    } else {
+        tracing::error!("Unhandled middleware error: {}", err);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Unexpected internal error".to_string(),
+        )
+    }
+}
 
-    tracing_subscriber::registry()
-        .with(
-            EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "todo_axum_sqlite=debug,tower_http=debug,info".into()),
+/// Parses each entry of `values` with `parse`, logging and dropping any
+/// entry that doesn't parse rather than panicking the process over one bad
+/// env var.
+fn parse_env_list<T, E: std::fmt::Display>(
+    env_key: &str,
+    values: &[String],
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Vec<T> {
+    values
+        .iter()
+        .filter_map(|value| match parse(value) {
+            Ok(parsed) => Some(parsed),
+            Err(e) => {
+                tracing::warn!("Ignoring invalid {env_key} entry '{value}': {e}");
+                None
+            }
+        })
+        .collect()
+}
+
+fn build_cors_layer(config: &Config) -> CorsLayer {
+    let allow_origin = if config.cors_allowed_origins.is_empty() {
+        AllowOrigin::any()
+    } else {
+        AllowOrigin::list(parse_env_list(
+            "CORS_ALLOWED_ORIGINS",
+            &config.cors_allowed_origins,
+            |origin| origin.parse(),
+        ))
+    };
+
+    let allow_methods = if config.cors_allowed_methods.is_empty() {
+        tower_http::cors::AllowMethods::from(tower_http::cors::Any)
+    } else {
+        parse_env_list(
+            "CORS_ALLOWED_METHODS",
+            &config.cors_allowed_methods,
+            |method| method.parse::<axum::http::Method>(),
         )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+        .into()
+    };
 
-    tracing::info!("Starting server...");
+    let allow_headers = if config.cors_allowed_headers.is_empty() {
+        tower_http::cors::AllowHeaders::from(tower_http::cors::Any)
+    } else {
+        parse_env_list(
+            "CORS_ALLOWED_HEADERS",
+            &config.cors_allowed_headers,
+            |header| header.parse::<axum::http::HeaderName>(),
+        )
+        .into()
+    };
 
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
-    tracing::info!("DATABASE_URL from env: {}", database_url);
+    CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(allow_methods)
+        .allow_headers(allow_headers)
+}
 
+/// SQLite is the only backend that talks to a local file rather than a
+/// server, so it's also the only one that needs the file (and its parent
+/// directory) to exist before `sqlx` will open it.
+fn ensure_sqlite_file_exists(database_url: &str) {
     let db_path_str = database_url
         .strip_prefix("sqlite:")
-        .unwrap_or(&database_url);
+        .unwrap_or(database_url);
 
     let path = Path::new(db_path_str);
     tracing::info!(
@@ -58,7 +141,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
     }
 
-    match fs::OpenOptions::new().create(true).append(true).open(&path) {
+    match fs::OpenOptions::new().create(true).append(true).open(path) {
         Ok(_) => tracing::info!(
             "Successfully touched/opened database file via std::fs: {:?}",
             path
@@ -76,38 +159,119 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             );
         }
     }
+}
+
+/// Connects to `database_url` with the pool type its scheme calls for, runs
+/// that backend's migrations, and builds the todo/user repositories on top
+/// of the resulting pool. This is the only place that needs to know all
+/// three backends exist; everything past this point is `dyn` trait objects.
+async fn connect_repositories(
+    dialect: Dialect,
+    database_url: &str,
+) -> Result<(DynTodoRepository, DynUserRepository), Box<dyn std::error::Error>> {
+    match dialect {
+        Dialect::Sqlite => {
+            ensure_sqlite_file_exists(database_url);
+
+            tracing::info!("Connecting to database with sqlx: {}", database_url);
+            let pool = SqlitePoolOptions::new()
+                .max_connections(5)
+                .connect(database_url)
+                .await
+                .expect("Failed to create SQLite connection pool");
+
+            tracing::info!("Database pool created. Running migrations...");
+            sqlx::migrate!("./migrations/sqlite")
+                .run(&pool)
+                .await
+                .expect("Failed to run database migrations");
+            tracing::info!("Migrations complete.");
+
+            Ok((
+                Arc::new(SqliteTodoRepository::new(pool.clone())),
+                Arc::new(SqliteUserRepository::new(pool)),
+            ))
+        }
+        Dialect::Postgres => {
+            tracing::info!("Connecting to database with sqlx: {}", database_url);
+            let pool = PgPoolOptions::new()
+                .max_connections(5)
+                .connect(database_url)
+                .await
+                .expect("Failed to create Postgres connection pool");
+
+            tracing::info!("Database pool created. Running migrations...");
+            sqlx::migrate!("./migrations/postgres")
+                .run(&pool)
+                .await
+                .expect("Failed to run database migrations");
+            tracing::info!("Migrations complete.");
+
+            Ok((
+                Arc::new(PostgresTodoRepository::new(pool.clone())),
+                Arc::new(PostgresUserRepository::new(pool)),
+            ))
+        }
+        Dialect::MySql => {
+            tracing::info!("Connecting to database with sqlx: {}", database_url);
+            let pool = MySqlPoolOptions::new()
+                .max_connections(5)
+                .connect(database_url)
+                .await
+                .expect("Failed to create MySQL connection pool");
+
+            tracing::info!("Database pool created. Running migrations...");
+            sqlx::migrate!("./migrations/mysql")
+                .run(&pool)
+                .await
+                .expect("Failed to run database migrations");
+            tracing::info!("Migrations complete.");
+
+            Ok((
+                Arc::new(MySqlTodoRepository::new(pool.clone())),
+                Arc::new(MySqlUserRepository::new(pool)),
+            ))
+        }
+    }
+}
 
-    tracing::info!("Connecting to database with sqlx: {}", database_url);
-    let db_pool = SqlitePoolOptions::new()
-        .max_connections(5)
-        .connect(&database_url)
-        .await
-        .expect("Failed to create SQLite connection pool");
-
-    tracing::info!("Database pool created. Running schema setup...");
-
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS todos (
-            id TEXT PRIMARY KEY NOT NULL,
-            title TEXT NOT NULL,
-            description TEXT,
-            completed BOOLEAN NOT NULL DEFAULT FALSE,
-            created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
-            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
-        );
-        "#,
-    )
-    .execute(&db_pool)
-    .await
-    .expect("Failed to create todos table");
-
-    tracing::info!("Schema setup complete.");
-
-    let app_state = AppState { db_pool };
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    dotenv().ok();
+
+    tracing_subscriber::registry()
+        .with(
+            EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "todo_axum_sqlite=debug,tower_http=debug,info".into()),
+        )
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    tracing::info!("Starting server...");
+
+    let config = Config::from_env();
+    let jwt_secret = env::var("JWT_SECRET").expect("JWT_SECRET must be set");
+
+    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    tracing::info!("DATABASE_URL from env: {}", database_url);
+
+    let dialect = Dialect::from_database_url(&database_url)
+        .unwrap_or_else(|_| panic!("Unsupported DATABASE_URL scheme: {}", database_url));
+
+    let (todo_repository, user_repository) = connect_repositories(dialect, &database_url).await?;
+
+    let app_state = AppState {
+        jwt_secret,
+        todo_repository,
+        user_repository,
+    };
 
     let app = Router::new()
         .route("/", get(|| async { "Hello, World!" }))
+        .route("/health", get(health::health_check))
+        .route("/health/db", get(health::db_health_check))
+        .route("/auth/signup", post(handlers::signup))
+        .route("/auth/login", post(handlers::login))
         .route(
             "/todos",
             post(handlers::create_todo).get(handlers::get_todos),
@@ -115,9 +279,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route(
             "/todos/{id}",
             get(handlers::get_todo_by_id)
-                .put(handlers::update_todo)
+                .patch(handlers::patch_todo)
+                .put(handlers::put_todo)
                 .delete(handlers::delete_todo),
         )
+        .layer(RequestBodyLimitLayer::new(config.max_request_body_bytes))
+        .layer(
+            ServiceBuilder::new()
+                .layer(HandleErrorLayer::new(handle_middleware_error))
+                .timeout(Duration::from_secs(config.request_timeout_secs)),
+        )
+        .layer(build_cors_layer(&config))
+        .layer(TraceLayer::new_for_http())
         .with_state(app_state);
 
     let addr = SocketAddr::from(([127, 0, 0, 1], 3000));
@@ -8,6 +8,8 @@ use uuid::Uuid;
 pub struct Todo {
     #[serde(with = "uuid::serde::simple")]
     pub id: Uuid,
+    #[serde(with = "uuid::serde::simple")]
+    pub user_id: Uuid,
     pub title: String,
     pub description: Option<String>,
     pub completed: bool,
@@ -30,4 +32,60 @@ pub struct UpdateTodo {
     pub title: Option<String>,
     pub description: Option<String>,
     pub completed: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ReplaceTodo {
+    #[validate(length(min = 1, message = "Title cannot be empty"))]
+    pub title: String,
+    pub description: Option<String>,
+    pub completed: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ListTodosParams {
+    pub page: Option<u32>,
+    pub page_size: Option<u32>,
+    pub completed: Option<bool>,
+    pub sort: Option<String>,
+    pub order: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PaginatedTodos {
+    pub items: Vec<Todo>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total: i64,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct User {
+    #[serde(with = "uuid::serde::simple")]
+    pub id: Uuid,
+    pub username: String,
+    #[serde(skip_serializing)]
+    pub password_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct CreateUser {
+    #[validate(length(min = 3, message = "Username must be at least 3 characters"))]
+    pub username: String,
+    #[validate(length(min = 8, message = "Password must be at least 8 characters"))]
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct LoginUser {
+    #[validate(length(min = 1, message = "Username cannot be empty"))]
+    pub username: String,
+    #[validate(length(min = 1, message = "Password cannot be empty"))]
+    pub password: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuthResponse {
+    pub token: String,
 }
\ No newline at end of file
@@ -0,0 +1,112 @@
+mod mysql;
+mod postgres;
+mod sqlite;
+mod users;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::errors::{AppError, AppResult};
+use crate::models::{CreateTodo, ListTodosParams, PaginatedTodos, ReplaceTodo, Todo, UpdateTodo};
+
+pub use mysql::{MySqlTodoRepository, MySqlUserRepository};
+pub use postgres::{PostgresTodoRepository, PostgresUserRepository};
+pub use sqlite::{SqliteTodoRepository, SqliteUserRepository};
+pub use users::{DynUserRepository, UserRepository};
+
+/// Which storage backend `DATABASE_URL` selects. `main` uses this once, at
+/// startup, to decide which connection pool, migrations, and repository
+/// implementations to build; nothing past that point needs to branch on it
+/// again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dialect {
+    Sqlite,
+    Postgres,
+    MySql,
+}
+
+impl Dialect {
+    pub fn from_database_url(database_url: &str) -> AppResult<Self> {
+        if database_url.starts_with("sqlite:") {
+            Ok(Dialect::Sqlite)
+        } else if database_url.starts_with("postgres:") || database_url.starts_with("postgresql:") {
+            Ok(Dialect::Postgres)
+        } else if database_url.starts_with("mysql:") {
+            Ok(Dialect::MySql)
+        } else {
+            Err(AppError::InternalServerError)
+        }
+    }
+}
+
+/// Storage-agnostic operations the HTTP layer needs for todos. Each method
+/// mirrors one of the six todo handlers; implementations own all of the
+/// backend-specific SQL so `handlers.rs` never has to know which database
+/// it's talking to. Errors that mean "not found" or "not yours" are
+/// returned as `AppError::NotFound` / `AppError::Forbidden` directly, the
+/// same way the handlers used to raise them.
+#[async_trait]
+pub trait TodoRepository: Send + Sync {
+    async fn create(&self, user_id: Uuid, payload: &CreateTodo) -> AppResult<Todo>;
+    async fn list(&self, user_id: Uuid, params: &ListTodosParams) -> AppResult<PaginatedTodos>;
+    async fn get(&self, user_id: Uuid, id: Uuid) -> AppResult<Todo>;
+    async fn update(&self, user_id: Uuid, id: Uuid, payload: &UpdateTodo) -> AppResult<Todo>;
+    /// Full replacement with upsert semantics. Returns the resulting todo
+    /// plus whether it was newly created (`true`) or replaced in place.
+    async fn replace(&self, user_id: Uuid, id: Uuid, payload: &ReplaceTodo) -> AppResult<(Todo, bool)>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> AppResult<()>;
+}
+
+pub type DynTodoRepository = Arc<dyn TodoRepository>;
+
+/// Whitelists a user-supplied sort column, since column names can't be bound
+/// as query parameters and must be interpolated directly into the SQL text.
+pub(super) fn sanitize_sort_column(sort: Option<&str>) -> &'static str {
+    match sort {
+        Some("updated_at") => "updated_at",
+        Some("title") => "title",
+        _ => "created_at",
+    }
+}
+
+/// Whitelists a user-supplied sort order for the same reason as
+/// [`sanitize_sort_column`].
+pub(super) fn sanitize_sort_order(order: Option<&str>) -> &'static str {
+    match order {
+        Some("asc") | Some("ASC") => "ASC",
+        _ => "DESC",
+    }
+}
+
+/// Maps a `sqlx::Error` from a user-creation `INSERT` to `AppError`,
+/// translating a unique-constraint violation on `username` into a client
+/// error instead of letting it fall through to a 500 via `SqlxError`.
+pub(super) fn map_create_error(err: sqlx::Error, username: &str) -> AppError {
+    if let sqlx::Error::Database(ref db_err) = err {
+        if db_err.is_unique_violation() {
+            return AppError::Conflict(format!("username '{}' is already taken", username));
+        }
+    }
+    AppError::from(err)
+}
+
+pub(super) const MAX_PAGE_SIZE: u32 = 100;
+pub(super) const DEFAULT_PAGE_SIZE: u32 = 20;
+
+/// `page` and `page_size` come straight off the query string, so a crafted
+/// `?page=` large enough to overflow a `u32` multiplication must not panic
+/// (debug) or wrap (release) before it reaches the `LIMIT`/`OFFSET` binding.
+/// The offset is computed in `u64` and clamped back down, since no real
+/// result set has more than `u32::MAX` rows to skip anyway.
+pub(super) fn paginate(params: &ListTodosParams) -> (u32, u32, u32) {
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params
+        .page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (page as u64 - 1) * page_size as u64;
+    let offset = u32::try_from(offset).unwrap_or(u32::MAX);
+    (page, page_size, offset)
+}
@@ -0,0 +1,263 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::postgres::PgPool;
+use uuid::Uuid;
+
+use super::{
+    TodoRepository, UserRepository, map_create_error, paginate, sanitize_sort_column,
+    sanitize_sort_order,
+};
+use crate::errors::{AppError, AppResult};
+use crate::models::{
+    CreateTodo, ListTodosParams, PaginatedTodos, ReplaceTodo, Todo, UpdateTodo, User,
+};
+
+/// Postgres has a native `UUID` and `TIMESTAMPTZ` type, so unlike the SQLite
+/// backend, `models::Todo` can be decoded straight off the row with no
+/// string round-tripping.
+pub struct PostgresTodoRepository {
+    pool: PgPool,
+}
+
+impl PostgresTodoRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for PostgresTodoRepository {
+    async fn create(&self, user_id: Uuid, payload: &CreateTodo) -> AppResult<Todo> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let todo = sqlx::query_as::<_, Todo>(
+            r#"
+            INSERT INTO todos (id, user_id, title, description, completed, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(&payload.title)
+        .bind(&payload.description)
+        .bind(false)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(todo)
+    }
+
+    async fn list(&self, user_id: Uuid, params: &ListTodosParams) -> AppResult<PaginatedTodos> {
+        let (page, page_size, offset) = paginate(params);
+        let sort_column = sanitize_sort_column(params.sort.as_deref());
+        let sort_order = sanitize_sort_order(params.order.as_deref());
+
+        let total: i64 = if let Some(completed) = params.completed {
+            sqlx::query_scalar("SELECT COUNT(*) FROM todos WHERE user_id = $1 AND completed = $2")
+                .bind(user_id)
+                .bind(completed)
+                .fetch_one(&self.pool)
+                .await?
+        } else {
+            sqlx::query_scalar("SELECT COUNT(*) FROM todos WHERE user_id = $1")
+                .bind(user_id)
+                .fetch_one(&self.pool)
+                .await?
+        };
+
+        let (where_clause, limit_idx, offset_idx) = if params.completed.is_some() {
+            ("WHERE user_id = $1 AND completed = $2", "$3", "$4")
+        } else {
+            ("WHERE user_id = $1", "$2", "$3")
+        };
+
+        let sql = format!(
+            "SELECT id, user_id, title, description, completed, created_at, updated_at \
+             FROM todos {where_clause} ORDER BY {sort_column} {sort_order} LIMIT {limit_idx} OFFSET {offset_idx}"
+        );
+
+        let mut query = sqlx::query_as::<_, Todo>(&sql).bind(user_id);
+        if let Some(completed) = params.completed {
+            query = query.bind(completed);
+        }
+        let items = query
+            .bind(page_size as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(PaginatedTodos {
+            items,
+            page,
+            page_size,
+            total,
+        })
+    }
+
+    async fn get(&self, user_id: Uuid, id: Uuid) -> AppResult<Todo> {
+        let todo = sqlx::query_as::<_, Todo>(
+            "SELECT id, user_id, title, description, completed, created_at, updated_at FROM todos WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+        if todo.user_id != user_id {
+            return Err(AppError::Forbidden);
+        }
+
+        Ok(todo)
+    }
+
+    async fn update(&self, user_id: Uuid, id: Uuid, payload: &UpdateTodo) -> AppResult<Todo> {
+        let current = sqlx::query_as::<_, Todo>(
+            "SELECT id, user_id, title, description, completed, created_at, updated_at FROM todos WHERE id = $1",
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+        if current.user_id != user_id {
+            return Err(AppError::Forbidden);
+        }
+
+        let final_title = payload.title.clone().unwrap_or(current.title);
+        let final_description = payload.description.clone();
+        let final_completed = payload.completed.unwrap_or(current.completed);
+        let updated_at = Utc::now();
+
+        let todo = sqlx::query_as::<_, Todo>(
+            r#"
+            UPDATE todos
+            SET title = $1, description = $2, completed = $3, updated_at = $4
+            WHERE id = $5
+            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            "#,
+        )
+        .bind(final_title)
+        .bind(final_description)
+        .bind(final_completed)
+        .bind(updated_at)
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(todo)
+    }
+
+    async fn replace(&self, user_id: Uuid, id: Uuid, payload: &ReplaceTodo) -> AppResult<(Todo, bool)> {
+        let existing_owner: Option<Uuid> =
+            sqlx::query_scalar("SELECT user_id FROM todos WHERE id = $1")
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        if let Some(owner) = existing_owner {
+            if owner != user_id {
+                return Err(AppError::Forbidden);
+            }
+        }
+
+        let now = Utc::now();
+
+        let todo = sqlx::query_as::<_, Todo>(
+            r#"
+            INSERT INTO todos (id, user_id, title, description, completed, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $6)
+            ON CONFLICT (id) DO UPDATE SET
+                title = excluded.title,
+                description = excluded.description,
+                completed = excluded.completed,
+                updated_at = excluded.updated_at
+            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(&payload.title)
+        .bind(&payload.description)
+        .bind(payload.completed)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok((todo, existing_owner.is_none()))
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> AppResult<()> {
+        let owner: Option<Uuid> = sqlx::query_scalar("SELECT user_id FROM todos WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match owner {
+            None => return Err(AppError::NotFound),
+            Some(owner) if owner != user_id => return Err(AppError::Forbidden),
+            Some(_) => {}
+        }
+
+        sqlx::query("DELETE FROM todos WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+pub struct PostgresUserRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepository for PostgresUserRepository {
+    async fn create(&self, username: &str, password_hash: &str) -> AppResult<User> {
+        let id = Uuid::new_v4();
+        let now = Utc::now();
+
+        let user = sqlx::query_as::<_, User>(
+            r#"
+            INSERT INTO users (id, username, password_hash, created_at)
+            VALUES ($1, $2, $3, $4)
+            RETURNING id, username, password_hash, created_at
+            "#,
+        )
+        .bind(id)
+        .bind(username)
+        .bind(password_hash)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| map_create_error(e, username))?;
+
+        Ok(user)
+    }
+
+    async fn find_by_username(&self, username: &str) -> AppResult<Option<User>> {
+        let user = sqlx::query_as::<_, User>(
+            "SELECT id, username, password_hash, created_at FROM users WHERE username = $1",
+        )
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(user)
+    }
+
+    async fn ping(&self) -> AppResult<()> {
+        sqlx::query("SELECT 1").fetch_one(&self.pool).await?;
+        Ok(())
+    }
+}
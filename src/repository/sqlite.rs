@@ -0,0 +1,328 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqlitePool;
+use uuid::Uuid;
+
+use super::{
+    TodoRepository, UserRepository, map_create_error, paginate, sanitize_sort_column,
+    sanitize_sort_order,
+};
+use crate::errors::{AppError, AppResult};
+use crate::models::{
+    CreateTodo, ListTodosParams, PaginatedTodos, ReplaceTodo, Todo, UpdateTodo, User,
+};
+
+/// SQLite stores ids and foreign keys as `TEXT`, so every id round-trips
+/// through `Uuid::parse_str`/`to_string`.
+pub struct SqliteTodoRepository {
+    pool: SqlitePool,
+}
+
+#[derive(sqlx::FromRow)]
+struct TodoRow {
+    id: String,
+    user_id: String,
+    title: String,
+    description: Option<String>,
+    completed: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<TodoRow> for Todo {
+    type Error = AppError;
+
+    fn try_from(row: TodoRow) -> Result<Self, Self::Error> {
+        Ok(Todo {
+            id: Uuid::parse_str(&row.id)?,
+            user_id: Uuid::parse_str(&row.user_id)?,
+            title: row.title,
+            description: row.description,
+            completed: row.completed,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+impl SqliteTodoRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for SqliteTodoRepository {
+    async fn create(&self, user_id: Uuid, payload: &CreateTodo) -> AppResult<Todo> {
+        let id = Uuid::new_v4().to_string();
+        let user_id = user_id.to_string();
+        let now = Utc::now();
+
+        let row = sqlx::query_as::<_, TodoRow>(
+            r#"
+            INSERT INTO todos (id, user_id, title, description, completed, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(&payload.title)
+        .bind(&payload.description)
+        .bind(false)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Todo::try_from(row)
+    }
+
+    async fn list(&self, user_id: Uuid, params: &ListTodosParams) -> AppResult<PaginatedTodos> {
+        let (page, page_size, offset) = paginate(params);
+        let sort_column = sanitize_sort_column(params.sort.as_deref());
+        let sort_order = sanitize_sort_order(params.order.as_deref());
+        let user_id = user_id.to_string();
+
+        let where_clause = if params.completed.is_some() {
+            "WHERE user_id = ? AND completed = ?"
+        } else {
+            "WHERE user_id = ?"
+        };
+
+        let total: i64 = if let Some(completed) = params.completed {
+            sqlx::query_scalar("SELECT COUNT(*) FROM todos WHERE user_id = ? AND completed = ?")
+                .bind(&user_id)
+                .bind(completed)
+                .fetch_one(&self.pool)
+                .await?
+        } else {
+            sqlx::query_scalar("SELECT COUNT(*) FROM todos WHERE user_id = ?")
+                .bind(&user_id)
+                .fetch_one(&self.pool)
+                .await?
+        };
+
+        let sql = format!(
+            "SELECT id, user_id, title, description, completed, created_at, updated_at \
+             FROM todos {where_clause} ORDER BY {sort_column} {sort_order} LIMIT ? OFFSET ?"
+        );
+
+        let mut query = sqlx::query_as::<_, TodoRow>(&sql).bind(&user_id);
+        if let Some(completed) = params.completed {
+            query = query.bind(completed);
+        }
+        let rows = query
+            .bind(page_size as i64)
+            .bind(offset as i64)
+            .fetch_all(&self.pool)
+            .await?;
+
+        let items = rows
+            .into_iter()
+            .map(Todo::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PaginatedTodos {
+            items,
+            page,
+            page_size,
+            total,
+        })
+    }
+
+    async fn get(&self, user_id: Uuid, id: Uuid) -> AppResult<Todo> {
+        let id_str = id.to_string();
+
+        let row = sqlx::query_as::<_, TodoRow>(
+            "SELECT id, user_id, title, description, completed, created_at, updated_at FROM todos WHERE id = ?",
+        )
+        .bind(id_str)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+        if row.user_id != user_id.to_string() {
+            return Err(AppError::Forbidden);
+        }
+
+        Todo::try_from(row)
+    }
+
+    async fn update(&self, user_id: Uuid, id: Uuid, payload: &UpdateTodo) -> AppResult<Todo> {
+        let id_str = id.to_string();
+
+        let current = sqlx::query_as::<_, TodoRow>(
+            "SELECT id, user_id, title, description, completed, created_at, updated_at FROM todos WHERE id = ?",
+        )
+        .bind(&id_str)
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(AppError::NotFound)?;
+
+        if current.user_id != user_id.to_string() {
+            return Err(AppError::Forbidden);
+        }
+
+        let final_title = payload.title.clone().unwrap_or(current.title);
+        let final_description = payload.description.clone();
+        let final_completed = payload.completed.unwrap_or(current.completed);
+        let updated_at = Utc::now();
+
+        let row = sqlx::query_as::<_, TodoRow>(
+            r#"
+            UPDATE todos
+            SET title = ?, description = ?, completed = ?, updated_at = ?
+            WHERE id = ?
+            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            "#,
+        )
+        .bind(final_title)
+        .bind(final_description)
+        .bind(final_completed)
+        .bind(updated_at)
+        .bind(id_str)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Todo::try_from(row)
+    }
+
+    async fn replace(&self, user_id: Uuid, id: Uuid, payload: &ReplaceTodo) -> AppResult<(Todo, bool)> {
+        let id_str = id.to_string();
+        let user_id_str = user_id.to_string();
+
+        let existing_owner: Option<String> =
+            sqlx::query_scalar("SELECT user_id FROM todos WHERE id = ?")
+                .bind(&id_str)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        if let Some(ref owner) = existing_owner {
+            if *owner != user_id_str {
+                return Err(AppError::Forbidden);
+            }
+        }
+
+        let now = Utc::now();
+
+        let row = sqlx::query_as::<_, TodoRow>(
+            r#"
+            INSERT INTO todos (id, user_id, title, description, completed, created_at, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT(id) DO UPDATE SET
+                title = excluded.title,
+                description = excluded.description,
+                completed = excluded.completed,
+                updated_at = excluded.updated_at
+            RETURNING id, user_id, title, description, completed, created_at, updated_at
+            "#,
+        )
+        .bind(id_str)
+        .bind(user_id_str)
+        .bind(&payload.title)
+        .bind(&payload.description)
+        .bind(payload.completed)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let created = existing_owner.is_none();
+        Ok((Todo::try_from(row)?, created))
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> AppResult<()> {
+        let id_str = id.to_string();
+
+        let owner: Option<String> = sqlx::query_scalar("SELECT user_id FROM todos WHERE id = ?")
+            .bind(&id_str)
+            .fetch_optional(&self.pool)
+            .await?;
+
+        match owner {
+            None => return Err(AppError::NotFound),
+            Some(owner) if owner != user_id.to_string() => return Err(AppError::Forbidden),
+            Some(_) => {}
+        }
+
+        sqlx::query("DELETE FROM todos WHERE id = ?")
+            .bind(id_str)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct UserRow {
+    id: String,
+    username: String,
+    password_hash: String,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<UserRow> for User {
+    type Error = AppError;
+
+    fn try_from(row: UserRow) -> Result<Self, Self::Error> {
+        Ok(User {
+            id: Uuid::parse_str(&row.id)?,
+            username: row.username,
+            password_hash: row.password_hash,
+            created_at: row.created_at,
+        })
+    }
+}
+
+pub struct SqliteUserRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteUserRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserRepository for SqliteUserRepository {
+    async fn create(&self, username: &str, password_hash: &str) -> AppResult<User> {
+        let id = Uuid::new_v4().to_string();
+        let now = Utc::now();
+
+        let row = sqlx::query_as::<_, UserRow>(
+            r#"
+            INSERT INTO users (id, username, password_hash, created_at)
+            VALUES (?, ?, ?, ?)
+            RETURNING id, username, password_hash, created_at
+            "#,
+        )
+        .bind(id)
+        .bind(username)
+        .bind(password_hash)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| map_create_error(e, username))?;
+
+        User::try_from(row)
+    }
+
+    async fn find_by_username(&self, username: &str) -> AppResult<Option<User>> {
+        let row = sqlx::query_as::<_, UserRow>(
+            "SELECT id, username, password_hash, created_at FROM users WHERE username = ?",
+        )
+        .bind(username)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(User::try_from).transpose()
+    }
+
+    async fn ping(&self) -> AppResult<()> {
+        sqlx::query("SELECT 1").fetch_one(&self.pool).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::errors::AppResult;
+use crate::models::User;
+
+/// Storage-agnostic operations the auth handlers and the `/health/db`
+/// readiness probe need. Mirrors [`super::TodoRepository`]: implementations
+/// own all backend-specific SQL so `handlers.rs`/`health.rs` never have to
+/// know which database the user table lives in.
+#[async_trait]
+pub trait UserRepository: Send + Sync {
+    async fn create(&self, username: &str, password_hash: &str) -> AppResult<User>;
+    async fn find_by_username(&self, username: &str) -> AppResult<Option<User>>;
+    /// Confirms the pool backing this repository can still reach its
+    /// database. Used by the `/health/db` readiness probe.
+    async fn ping(&self) -> AppResult<()>;
+}
+
+pub type DynUserRepository = std::sync::Arc<dyn UserRepository>;